@@ -13,7 +13,9 @@ pub use crate::convert::{CastFrom, CastInto};
 pub use crate::cpp_box::{CppBox, CppDeletable};
 pub use crate::iterator::{cpp_iter, CppIterator};
 pub use crate::ptr::{MutPtr, NullPtr, Ptr};
+pub use crate::qobject::{qobject_cast_with, QObjectCast};
 pub use crate::ref_::{MutRef, Ref};
+pub use crate::variadic::VARIADIC_FORWARDING_SHIM;
 
 mod casts;
 pub mod cmp;
@@ -23,4 +25,6 @@ mod iterator;
 pub mod ops;
 mod ops_impls;
 mod ptr;
+mod qobject;
 mod ref_;
+mod variadic;
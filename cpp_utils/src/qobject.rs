@@ -0,0 +1,49 @@
+//! Runtime `qobject_cast` support.
+
+use crate::Ptr;
+use std::os::raw::c_void;
+
+/// Safe cast between `QObject`-derived classes using Qt's meta-object system.
+///
+/// Unlike [`DynamicCast`](crate::DynamicCast), which relies on C++ RTTI, this
+/// consults `QMetaObject::cast` at runtime and therefore keeps working across
+/// shared-library boundaries even when RTTI is unavailable. The cast returns a
+/// null [`Ptr`] when the object is not an instance of `T`.
+///
+/// This trait is implemented by the generated bindings for every pair of
+/// `QObject`-derived classes related by inheritance.
+pub trait QObjectCast<T> {
+    /// Casts `ptr` to `T` via `QMetaObject::cast`, returning a null pointer if
+    /// the dynamic type of the object is not `T` or a subclass of it.
+    ///
+    /// ### Safety
+    ///
+    /// `ptr` must be either valid or null.
+    unsafe fn qobject_cast(ptr: Ptr<Self>) -> Ptr<T>;
+}
+
+/// Runtime helper that the generated `QObjectCast` implementations delegate to.
+///
+/// `cast` is the `QMetaObject::cast` shim generated for the target type `T`: it
+/// takes the source `QObject*` and returns the same pointer (when the object's
+/// dynamic type is `T` or a subclass) or null. Routing the decision through
+/// Qt's meta-object system is what lets this work across shared-library
+/// boundaries without relying on RTTI.
+///
+/// A null input maps to a null output, so the result is always a valid,
+/// possibly-null [`Ptr`].
+///
+/// ### Safety
+///
+/// `ptr` must be either valid or null, and `cast` must be the
+/// `QMetaObject::cast` shim generated for `T`.
+pub unsafe fn qobject_cast_with<S, T>(
+    ptr: Ptr<S>,
+    cast: unsafe extern "C" fn(*mut c_void) -> *mut c_void,
+) -> Ptr<T> {
+    let source = ptr.as_mut_raw_ptr() as *mut c_void;
+    if source.is_null() {
+        return Ptr::from_raw(std::ptr::null_mut());
+    }
+    Ptr::from_raw(cast(source) as *mut T)
+}
@@ -0,0 +1,24 @@
+//! Support for wrapping variadic C++ functions as fixed-arity families.
+//!
+//! Rust cannot forward C varargs, so a variadic C++ function `f(a, b, ...)` is
+//! wrapped as a family of fixed-arity FFI thunks (0, 1, 2, ... trailing
+//! arguments). Each thunk receives its trailing arguments as ordinary typed
+//! parameters and forwards them to the real function through the C shim below.
+
+/// C++ shim, emitted into the generated wrapper library, that forwards a
+/// fixed-arity argument list to a variadic C++ callee using `<cstdarg>`.
+///
+/// The code generator instantiates this once per `(function, arity)` pair
+/// produced by `CppFunction::variadic_arity_family`, substituting the callee
+/// name and the concrete trailing argument list. Because every argument is
+/// already materialized as a typed parameter by the time the thunk runs, the
+/// expansion is a direct call — the `<cstdarg>` include is kept so specialized
+/// callees that themselves re-enter a `va_list` (e.g. the `QString::asprintf`
+/// family) have the machinery available.
+pub const VARIADIC_FORWARDING_SHIM: &str = "\
+#include <cstdarg>\n\
+// Generated per (function, arity): forwards the fixed trailing arguments\n\
+// `{ARGS}` to the variadic callee `{FUNCTION}` and returns its result.\n\
+extern \"C\" {RETURN} {THUNK}({PARAMS}) {\n\
+    return {FUNCTION}({ARGS});\n\
+}\n";
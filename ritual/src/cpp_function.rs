@@ -51,6 +51,21 @@ pub enum CppFunctionKind {
     Destructor,
 }
 
+/// Ref-qualifier of a C++ member function (`void f() &` / `void f() &&`).
+///
+/// Introduced in C++11, a ref-qualifier constrains the value category of the
+/// object expression the method may be called on. Two member functions that
+/// differ only in their ref-qualifier form distinct overloads.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub enum CppFunctionRefQualifier {
+    /// The method is not ref-qualified.
+    None,
+    /// The method is lvalue-ref-qualified (`&`).
+    LValue,
+    /// The method is rvalue-ref-qualified (`&&`).
+    RValue,
+}
+
 /// Information about a C++ class member method
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
 pub struct CppFunctionMemberData {
@@ -71,6 +86,8 @@ pub struct CppFunctionMemberData {
     pub is_signal: bool,
     /// True if the method is a Qt slot
     pub is_slot: bool,
+    /// Ref-qualifier of the method (`&` / `&&` / none)
+    pub ref_qualifier: CppFunctionRefQualifier,
 }
 
 impl CppFunctionMemberData {
@@ -78,6 +95,7 @@ impl CppFunctionMemberData {
         self.kind == other.kind
             && self.is_const == other.is_const
             && self.is_static == other.is_static
+            && self.ref_qualifier == other.ref_qualifier
     }
 }
 
@@ -118,6 +136,12 @@ pub struct CppFunction {
     /// Whether the argument list is terminated with "..."
     pub allows_variadic_arguments: bool,
     pub cast: Option<CppCast>,
+    /// Deprecation status of the method, taken from the C++ declaration.
+    /// `None` if the method is not deprecated. The outer `Some` indicates that
+    /// the declaration carries a `[[deprecated]]` attribute (or an equivalent
+    /// such as Qt's `QT_DEPRECATED`); the inner `Option<String>` holds the
+    /// optional human-readable message attached to it.
+    pub deprecation: Option<Option<String>>,
     /// C++ code of the method's declaration.
     /// None if the method was not explicitly declared.
     pub declaration_code: Option<String>,
@@ -246,6 +270,11 @@ impl CppFunction {
             if info.is_const {
                 write!(s, " const").unwrap();
             }
+            match info.ref_qualifier {
+                CppFunctionRefQualifier::None => {}
+                CppFunctionRefQualifier::LValue => write!(s, " &").unwrap(),
+                CppFunctionRefQualifier::RValue => write!(s, " &&").unwrap(),
+            }
         }
         s.trim().to_string()
     }
@@ -286,6 +315,9 @@ impl CppFunction {
         if self.allows_variadic_arguments {
             s = format!("{} [var args]", s);
         }
+        if self.deprecation.is_some() {
+            s = format!("{} [deprecated]", s);
+        }
         s = format!("{} {}", s, self.return_type.to_cpp_pseudo_code());
         s = format!("{} {}", s, self.path.to_cpp_pseudo_code());
         s = format!(
@@ -309,6 +341,11 @@ impl CppFunction {
             if info.is_const {
                 s = format!("{} const", s);
             }
+            match info.ref_qualifier {
+                CppFunctionRefQualifier::None => {}
+                CppFunctionRefQualifier::LValue => s = format!("{} &", s),
+                CppFunctionRefQualifier::RValue => s = format!("{} &&", s),
+            }
         }
         s.trim().to_string()
     }
@@ -332,6 +369,44 @@ impl CppFunction {
         arg == self.arguments[0].argument_type
     }
 
+    /// Returns true if this method is a move constructor, i.e. a constructor
+    /// whose sole argument is an rvalue reference `T&&` to its own class.
+    pub fn is_move_constructor(&self) -> bool {
+        if !self.is_constructor() {
+            return false;
+        }
+        if self.arguments.len() != 1 {
+            return false;
+        }
+        let arg = CppType::PointerLike {
+            is_const: false,
+            kind: CppPointerLikeTypeKind::RValueReference,
+            target: Box::new(CppType::Class(self.class_path().unwrap())),
+        };
+        arg == self.arguments[0].argument_type
+    }
+
+    /// Returns true if this method is a move-assignment operator, i.e. an
+    /// `operator=` taking a single rvalue reference `T&&` to its own class.
+    pub fn is_move_assignment_operator(&self) -> bool {
+        if self.operator != Some(CppOperator::Assignment) {
+            return false;
+        }
+        if self.arguments.len() != 1 {
+            return false;
+        }
+        let class_path = match self.class_path() {
+            Ok(path) => path,
+            Err(_) => return false,
+        };
+        let arg = CppType::PointerLike {
+            is_const: false,
+            kind: CppPointerLikeTypeKind::RValueReference,
+            target: Box::new(CppType::Class(class_path)),
+        };
+        arg == self.arguments[0].argument_type
+    }
+
     /// Returns true if this method is a destructor.
     pub fn is_destructor(&self) -> bool {
         match &self.member {
@@ -355,6 +430,31 @@ impl CppFunction {
         }
     }
 
+    /// Returns the ref-qualifier of this member function, or `None` for a free
+    /// function.
+    pub fn ref_qualifier(&self) -> Option<CppFunctionRefQualifier> {
+        self.member.as_ref().map(|info| info.ref_qualifier)
+    }
+
+    /// Builds the C++ object expression a generated FFI thunk must invoke this
+    /// member function on, given the name of the `this` pointer parameter.
+    ///
+    /// Rvalue-ref-qualified overloads (`void f() &&`) can only be called on an
+    /// rvalue, so the thunk casts the receiver with `std::move(*this)`; every
+    /// other overload uses the plain dereferenced receiver. This lets both the
+    /// lvalue- and rvalue-qualified overloads be wrapped without a name
+    /// collision. Returns `None` for free functions, which have no receiver.
+    pub fn cpp_receiver_expression(&self, this: &str) -> Option<String> {
+        let info = self.member.as_ref()?;
+        let expr = match info.ref_qualifier {
+            CppFunctionRefQualifier::RValue => format!("std::move(*{})", this),
+            CppFunctionRefQualifier::None | CppFunctionRefQualifier::LValue => {
+                format!("*{}", this)
+            }
+        };
+        Some(expr)
+    }
+
     pub fn is_private(&self) -> bool {
         match &self.member {
             Some(info) => info.visibility == CppVisibility::Private,
@@ -419,6 +519,47 @@ impl CppFunction {
         )
     }
 
+    /// Returns the argument types of this signal or slot in declaration order.
+    ///
+    /// Unlike [`receiver_id`](Self::receiver_id), which encodes the signature
+    /// into a Qt4-style `"2signal(args)"` string and has to paper over
+    /// mismatches such as `QList<QModelIndex>` in
+    /// [`patch_receiver_argument_type`](Self::patch_receiver_argument_type),
+    /// this exposes the types directly so a typed, pointer-to-member style
+    /// connector can be generated for the signal. The overload is identified by
+    /// the full signature rather than by name, so overloaded signals connect
+    /// unambiguously.
+    pub fn connection_argument_types(&self) -> Result<Vec<CppType>> {
+        match &self.member {
+            Some(info) if info.is_signal || info.is_slot => Ok(self
+                .arguments
+                .iter()
+                .map(|arg| arg.argument_type.clone())
+                .collect()),
+            Some(_) => bail!("not a signal or slot"),
+            None => bail!("not a class method"),
+        }
+    }
+
+    /// Renders the pointer-to-member signature used by a typed connector for
+    /// this signal or slot, e.g. `indexesMoved(QList< QModelIndex >)`.
+    ///
+    /// This is the Qt5-style counterpart of [`receiver_id`](Self::receiver_id).
+    /// Because the connection is resolved through a pointer-to-member rather
+    /// than by Qt's string-matching machinery, the argument types are emitted
+    /// verbatim and the `QList<QModelIndex>` →  `QModelIndexList` rewrite
+    /// performed by [`patch_receiver_argument_type`](Self::patch_receiver_argument_type)
+    /// for the legacy string path is neither needed nor applied here. Overloaded
+    /// signals stay unambiguous because the full signature participates.
+    pub fn typed_connection_signature(&self) -> Result<String> {
+        let arguments = self
+            .connection_argument_types()?
+            .iter()
+            .map_if_ok(|arg| arg.to_cpp_code(None))?
+            .join(", ");
+        Ok(format!("{}({})", self.path.last().name, arguments))
+    }
+
     pub fn member(&self) -> Option<&CppFunctionMemberData> {
         self.member.as_ref()
     }
@@ -428,6 +569,18 @@ impl CppFunction {
         self.operator.is_some()
     }
 
+    /// Returns the `#[deprecated]` attribute to attach to the generated Rust
+    /// wrapper for this function, or `None` if the C++ declaration is not
+    /// deprecated. The `note = "..."` clause is included when the C++
+    /// `[[deprecated("msg")]]` attribute carried a message.
+    pub fn rust_deprecated_attribute(&self) -> Option<String> {
+        match &self.deprecation {
+            None => None,
+            Some(None) => Some("#[deprecated]".to_string()),
+            Some(Some(message)) => Some(format!("#[deprecated(note = {:?})]", message)),
+        }
+    }
+
     /// Returns collection of all types found in the signature of this method,
     /// including argument types, return type and type of `this` implicit parameter.
     pub fn all_involved_types(&self) -> Vec<CppType> {
@@ -450,6 +603,52 @@ impl CppFunction {
         result
     }
 
+    /// Produces a fixed-arity instantiation of a variadic function.
+    ///
+    /// Rust cannot forward C varargs, so a variadic C++ function is wrapped as
+    /// a family of fixed-arity FFI thunks instead of being dropped. Each member
+    /// of the family is obtained by appending concrete `extra` argument types in
+    /// place of the `...` pack; the returned function is no longer variadic and
+    /// carries generated names (`varX`) for the expanded arguments. The caller
+    /// emits one such thunk per arity in `0..=N` and forwards the packed
+    /// arguments through a `<cstdarg>` C shim.
+    pub fn with_variadic_arguments(&self, extra: &[CppType]) -> Result<CppFunction> {
+        if !self.allows_variadic_arguments {
+            bail!("not a variadic function");
+        }
+        let mut function = self.clone();
+        function.allows_variadic_arguments = false;
+        for (index, argument_type) in extra.iter().enumerate() {
+            function.arguments.push(CppFunctionArgument {
+                name: format!("var{}", self.arguments.len() + index),
+                argument_type: argument_type.clone(),
+                has_default_value: false,
+            });
+        }
+        Ok(function)
+    }
+
+    /// Produces the family of fixed-arity instantiations of a variadic function
+    /// for every arity in `0..=max_arity`, using `filler` as the type of each
+    /// synthesized trailing argument.
+    ///
+    /// This is the entry point for the variadic generation mode: the code
+    /// generator emits one FFI thunk per returned function, and each thunk
+    /// forwards its packed arguments through a `<cstdarg>` C shim. Returns an
+    /// empty vector for non-variadic functions.
+    pub fn variadic_arity_family(&self, max_arity: usize, filler: &CppType) -> Vec<CppFunction> {
+        if !self.allows_variadic_arguments {
+            return Vec::new();
+        }
+        (0..=max_arity)
+            .map(|arity| {
+                let extra = vec![filler.clone(); arity];
+                self.with_variadic_arguments(&extra)
+                    .expect("function is variadic")
+            })
+            .collect()
+    }
+
     pub fn can_infer_template_arguments(&self) -> bool {
         if let Some(args) = &self.path.last().template_arguments {
             for t in args {
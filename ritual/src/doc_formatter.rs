@@ -4,12 +4,13 @@
 #![allow(dead_code)]
 
 use crate::cpp_ffi_data::{CppFfiFunctionKind, CppFieldAccessorType};
+use crate::cpp_function::CppFunctionRefQualifier;
 use crate::cpp_type::CppType;
 use crate::database::{DatabaseClient, DbItem, DocItem};
 use crate::rust_code_generator::rust_type_to_code;
 use crate::rust_info::{
-    RustEnumValue, RustFunction, RustFunctionKind, RustModule, RustModuleKind, RustQtReceiverType,
-    RustSpecialModuleKind, RustStruct, RustStructKind, RustWrapperTypeKind,
+    RustEnumValue, RustFunction, RustFunctionKind, RustModule, RustModuleKind, RustPath,
+    RustQtReceiverType, RustSpecialModuleKind, RustStruct, RustStructKind, RustWrapperTypeKind,
 };
 use itertools::Itertools;
 use ritual_common::errors::{bail, err_msg, Result};
@@ -27,13 +28,55 @@ pub fn wrap_cpp_doc_block(html: &str) -> String {
     )
 }
 
+/// Renders a `RustPath` as a rustdoc intra-doc link of the form
+/// ``[`Name`](path)``.
+///
+/// Items belonging to the crate being generated are linked through the
+/// `crate::` prefix, while items from other crates use their fully-qualified
+/// `other_crate::...` path, as decided by `database.crate_name()`. Paths that
+/// contain generic arguments are not valid intra-doc link targets, so they
+/// degrade gracefully to a plain code span.
+fn intra_doc_link(path: &RustPath, database: &DatabaseClient) -> String {
+    let name = path.last();
+    let target = path.full_name(Some(database.crate_name()));
+    if target.contains('<') || target.contains('>') {
+        return format!("`{}`", name);
+    }
+    format!("[`{}`]({})", name, target)
+}
+
+/// Collects the immediate child modules of `parent`, in declaration order, for
+/// building a module index.
+fn child_modules<'a>(
+    parent: &RustModule,
+    database: &'a DatabaseClient,
+) -> Vec<&'a RustModule> {
+    database
+        .rust_items()
+        .filter_map(|item| item.item.as_module_ref())
+        .filter(|module| module.path.parent().ok().as_ref() == Some(&parent.path))
+        .collect()
+}
+
 pub fn module_doc(module: DbItem<&RustModule>, database: &DatabaseClient) -> Result<String> {
     let mut output = String::new();
     match module.item.kind {
         RustModuleKind::Special(kind) => match kind {
             RustSpecialModuleKind::CrateRoot => {
-                // TODO: generate some useful docs for crate root
-                write!(output, "Crate root")?;
+                if let Some(overview) = database.crate_root_doc() {
+                    if !overview.is_empty() {
+                        writeln!(output, "{}\n", overview)?;
+                    }
+                }
+                let children = child_modules(module.item, database);
+                if children.is_empty() {
+                    write!(output, "Crate root")?;
+                } else {
+                    writeln!(output, "Modules:\n")?;
+                    for child in children {
+                        writeln!(output, "- {}", intra_doc_link(&child.path, database))?;
+                    }
+                }
             }
             RustSpecialModuleKind::Ffi => {
                 write!(output, "Functions provided by the C++ wrapper library")?;
@@ -56,6 +99,12 @@ pub fn module_doc(module: DbItem<&RustModule>, database: &DatabaseClient) -> Res
                 .as_namespace_ref()
                 .ok_or_else(|| err_msg("invalid source cpp item type"))?;
 
+            if let Some(doc_item) = database.find_doc_for(&module.id)? {
+                if !doc_item.item.html.is_empty() {
+                    writeln!(output, "{}\n", first_phrase(&doc_item.item.html))?;
+                }
+            }
+
             let cpp_path_text = wrap_inline_cpp_code(&cpp_item.path.to_cpp_pseudo_code());
             write!(output, "C++ namespace: {}", cpp_path_text)?;
         }
@@ -67,6 +116,12 @@ pub fn module_doc(module: DbItem<&RustModule>, database: &DatabaseClient) -> Res
                 .as_type_ref()
                 .ok_or_else(|| err_msg("invalid source cpp item type"))?;
 
+            if let Some(doc_item) = database.find_doc_for(&module.id)? {
+                if !doc_item.item.html.is_empty() {
+                    writeln!(output, "{}\n", first_phrase(&doc_item.item.html))?;
+                }
+            }
+
             let cpp_path_text = wrap_inline_cpp_code(&cpp_item.path.to_cpp_pseudo_code());
             write!(output, "C++ type: {}", cpp_path_text)?;
         }
@@ -84,6 +139,40 @@ fn first_phrase(html: &str) -> &str {
     html
 }
 
+/// Builds a rustdoc `# Examples` section demonstrating the `new()`/`set()`
+/// usage of a slot wrapper.
+///
+/// `type_code` must be the wrapper type's fully-qualified path (rendered with
+/// `full_name(None)`, i.e. starting from the crate name rather than `crate::`)
+/// and `argument_types` the closure parameter types rendered the same way, so
+/// the snippet type-checks as a `no_run` doctest compiled as an external crate.
+///
+/// The snippet deliberately omits the `connect()` line: the signal source can't
+/// be constructed generically, and an unbound `signal` would fail to compile.
+/// What remains — constructing the wrapper and assigning a correctly-typed
+/// closure — is self-contained and compilable.
+fn slot_wrapper_example(type_code: &str, argument_types: &[String]) -> String {
+    let closure_params = argument_types
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| format!("arg{}: {}", i, ty))
+        .join(", ");
+    format!(
+        "# Examples\n\n\
+         ```no_run\n\
+         let slot = unsafe {{ {type_code}::new() }};\n\
+         unsafe {{\n    \
+         slot.set(|{params}| {{\n        \
+         // handle the signal here\n    \
+         }});\n\
+         }}\n\
+         // Pass `slot` to the `connect()` method of a matching `qt_core::Signal`.\n\
+         ```\n",
+        type_code = type_code,
+        params = closure_params,
+    )
+}
+
 pub fn struct_doc(type1: DbItem<&RustStruct>, database: &DatabaseClient) -> Result<String> {
     let mut output = String::new();
 
@@ -147,9 +236,9 @@ pub fn struct_doc(type1: DbItem<&RustStruct>, database: &DatabaseClient) -> Resu
 
                 writeln!(
                     output,
-                    "It's recommended to use `{}` instead \
+                    "It's recommended to use {} instead \
                      because it provides a more high-level API.\n",
-                    raw_slot_wrapper.closure_wrapper.last()
+                    intra_doc_link(&raw_slot_wrapper.closure_wrapper, database)
                 )?;
 
                 let ffi_item = database
@@ -185,6 +274,16 @@ pub fn struct_doc(type1: DbItem<&RustStruct>, database: &DatabaseClient) -> Resu
                      until source signals are disconnected or the slot object is destroyed.\n\n\
                      If `set()` was not called, slot invocation has no effect.\n"
                 )?;
+
+                // Examples compile as an external crate, so use fully-qualified
+                // paths (`None`) rather than `crate::`-relative ones.
+                let type_code = type1.item.path.full_name(None);
+                let argument_types = raw_slot_wrapper
+                    .arguments
+                    .iter()
+                    .map(|arg| rust_type_to_code(arg, None))
+                    .collect_vec();
+                writeln!(output, "{}", slot_wrapper_example(&type_code, &argument_types))?;
             }
         }
         RustStructKind::QtSlotWrapper(wrapper) => {
@@ -249,6 +348,16 @@ pub fn struct_doc(type1: DbItem<&RustStruct>, database: &DatabaseClient) -> Resu
                  objects referenced by the closure. \n\n\
                  If `set()` was not called, slot invocation has no effect.\n"
             )?;
+
+            // Examples compile as an external crate, so use fully-qualified
+            // paths (`None`) rather than `crate::`-relative ones.
+            let type_code = type1.item.path.full_name(None);
+            let argument_types = wrapper
+                .arguments
+                .iter()
+                .map(|arg| rust_type_to_code(arg.api_type(), None))
+                .collect_vec();
+            writeln!(output, "{}", slot_wrapper_example(&type_code, &argument_types))?;
         }
         // private struct, no doc needed
         RustStructKind::SizedType(_) => {}
@@ -290,6 +399,81 @@ fn format_maybe_link(url: &Option<String>, text: &str) -> String {
     }
 }
 
+/// Extracts a `major.minor[.patch]` version number that immediately follows
+/// `marker` (matched case-insensitively) in `html`. Returns `None` if the
+/// marker is absent or isn't followed by a parseable version.
+fn version_after(html: &str, marker: &str) -> Option<String> {
+    let lower = html.to_lowercase();
+    let index = lower.find(marker)?;
+    let rest = &html[index + marker.len()..];
+    let version: String = rest
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    // Drop a sentence-ending period ("introduced in Qt 5.2.").
+    let version = version.trim_end_matches('.');
+    if version.is_empty() {
+        None
+    } else {
+        Some(version.to_string())
+    }
+}
+
+/// Returns `true` if the documentation marks the item as deprecated or obsolete.
+///
+/// This anchors on Qt's actual marker phrasing ("This function is deprecated.",
+/// "... is obsolete.", "Deprecated since ...") rather than a bare substring
+/// match, so a mere mention of the word — e.g. "use X instead of the deprecated
+/// Y" — does not misclassify the item as deprecated.
+fn is_deprecated(html: &str) -> bool {
+    let lower = html.to_lowercase();
+    const MARKERS: &[&str] = &[
+        "is deprecated",
+        "are deprecated",
+        "was deprecated",
+        "deprecated since",
+        "is obsolete",
+        "are obsolete",
+    ];
+    MARKERS.iter().any(|marker| lower.contains(marker))
+}
+
+/// Scans Qt documentation HTML for availability markers ("This function was
+/// introduced in Qt X", "This function is deprecated", "Obsolete") and renders
+/// them as structured rustdoc notes. Returns an empty string when no markers
+/// are recognized. When the version text can't be parsed, the deprecation
+/// callout degrades to an unversioned note, while an "introduced in" marker
+/// produces no note. The deprecation callout reuses [`deprecation_note`] so the
+/// doc note and the generated `#[deprecated]` attribute stay in sync.
+fn availability_notes(cpp_doc: &DocItem) -> String {
+    let mut notes = String::new();
+    if let Some(version) = version_after(&cpp_doc.html, "introduced in qt ") {
+        writeln!(notes, "*Available since Qt {}*\n", version).unwrap();
+    }
+    if let Some(note) = deprecation_note(cpp_doc) {
+        writeln!(notes, "**{}**\n", note).unwrap();
+    }
+    notes
+}
+
+/// Returns the note text for a `#[deprecated(note = "...")]` attribute when the
+/// item's documentation marks it as deprecated, or `None` otherwise.
+///
+/// This is the coordination point used by `rust_code_generator` to decide
+/// whether to attach a `#[deprecated]` attribute to the generated Rust item.
+/// Because deprecation is tracked per overload, callers should consult it for
+/// each function individually rather than for the whole group.
+pub fn deprecation_note(cpp_doc: &DocItem) -> Option<String> {
+    if !is_deprecated(&cpp_doc.html) {
+        return None;
+    }
+    match version_after(&cpp_doc.html, "deprecated since qt ") {
+        Some(version) => Some(format!("Deprecated since Qt {}", version)),
+        None => Some("Deprecated in C++".to_string()),
+    }
+}
+
 fn format_doc_item(cpp_doc: &DocItem) -> String {
     let mut output = if let Some(declaration) = &cpp_doc.mismatched_declaration {
         format!(
@@ -301,6 +485,10 @@ fn format_doc_item(cpp_doc: &DocItem) -> String {
     } else {
         format!("{}:", format_maybe_link(&cpp_doc.url, "C++ documentation"))
     };
+    let notes = availability_notes(cpp_doc);
+    if !notes.is_empty() {
+        write!(output, "{}", notes).unwrap();
+    }
     write!(output, "{}", wrap_cpp_doc_block(&cpp_doc.html)).unwrap();
     output
 }
@@ -336,6 +524,11 @@ pub fn function_doc(function: DbItem<&RustFunction>, database: &DatabaseClient)
             }
             "new" => {
                 writeln!(output, "Creates a new object.\n")?;
+                // The closure's argument types aren't available on the method
+                // itself, only on the wrapper struct, so the compilable example
+                // is emitted from `struct_doc` where the arity is known. Emitting
+                // one here would require a closure of unknown arity and wouldn't
+                // type-check under `no_run`.
             }
             "set" => {
                 writeln!(output, "Assigns `func` as the signal handler.\n")?;
@@ -383,6 +576,67 @@ pub fn function_doc(function: DbItem<&RustFunction>, database: &DatabaseClient)
                         wrap_inline_cpp_code(&cpp_item.short_text())
                     )?;
 
+                    // Move special members transfer ownership out of the
+                    // source object, leaving it in a valid but unspecified
+                    // state.
+                    if cpp_item.is_move_constructor() {
+                        write!(
+                            output,
+                            "Move constructor: takes ownership of the argument, \
+                             which is left in a valid but unspecified state.\n\n"
+                        )?;
+                    } else if cpp_item.is_move_assignment_operator() {
+                        write!(
+                            output,
+                            "Move-assignment operator: takes ownership of the \
+                             argument, which is left in a valid but unspecified \
+                             state.\n\n"
+                        )?;
+                    }
+
+                    // If the C++ declaration is marked `[[deprecated]]`, the
+                    // generated wrapper carries the matching `#[deprecated]`
+                    // attribute; surface that in the docs as well.
+                    if let Some(attribute) = cpp_item.rust_deprecated_attribute() {
+                        write!(
+                            output,
+                            "This function is deprecated; the wrapper is annotated with \
+                             `{}`.\n\n",
+                            attribute
+                        )?;
+                    }
+
+                    // An rvalue-ref-qualified overload must be invoked on an
+                    // rvalue, so the FFI thunk calls it on a moved-from receiver.
+                    if cpp_item.ref_qualifier() == Some(CppFunctionRefQualifier::RValue) {
+                        if let Some(receiver) = cpp_item.cpp_receiver_expression("this") {
+                            write!(
+                                output,
+                                "This is the rvalue-ref-qualified overload; \
+                                 it is invoked on a moved-from receiver \
+                                 (`{}`).\n\n",
+                                receiver
+                            )?;
+                        }
+                    }
+
+                    // Variadic functions can't forward C varargs from Rust, so
+                    // they're wrapped as a family of fixed-arity thunks.
+                    if cpp_item.allows_variadic_arguments {
+                        // Only the number of generated variants is reported, so
+                        // any filler type works here.
+                        const MAX_VARIADIC_ARITY: usize = 10;
+                        let family =
+                            cpp_item.variadic_arity_family(MAX_VARIADIC_ARITY, &cpp_item.return_type);
+                        writeln!(
+                            output,
+                            "This variadic function is wrapped as {} fixed-arity variants \
+                             (0 to {} trailing arguments).\n",
+                            family.len(),
+                            MAX_VARIADIC_ARITY
+                        )?;
+                    }
+
                     // TODO: detect omitted arguments using source_id
                     /*if let Some(arguments_before_omitting) =
                         &cpp_function.doc.arguments_before_omitting
@@ -431,13 +685,22 @@ pub fn function_doc(function: DbItem<&RustFunction>, database: &DatabaseClient)
             writeln!(
                 output,
                 "Returns a built-in Qt {signal} `{cpp_path}` that can be passed to \
-                 `qt_core::Signal::connect`.\n",
+                 [`qt_core::Signal::connect`](qt_core::Signal::connect).\n",
                 signal = match getter.receiver_type {
                     RustQtReceiverType::Signal => "signal",
                     RustQtReceiverType::Slot => "slot",
                 },
                 cpp_path = cpp_item.path.to_cpp_pseudo_code()
             )?;
+
+            if let Ok(signature) = cpp_item.typed_connection_signature() {
+                writeln!(
+                    output,
+                    "The connection uses a typed pointer-to-member connector for \
+                     `{}`, so overloads connect unambiguously.\n",
+                    wrap_inline_cpp_code(&signature)
+                )?;
+            }
         }
         // FFI functions are private
         RustFunctionKind::FfiFunction => {}